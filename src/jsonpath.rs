@@ -0,0 +1,317 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::{JsonError, JsonValue};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Wildcard,
+    Index(isize),
+    Slice(Option<isize>, Option<isize>),
+    RecursiveKey(String),
+}
+
+/// Evaluates a JSONPath expression against a parsed document, returning every matching node.
+///
+/// Supports `$` (root), `.key` / `['key']` child access, `*` wildcards, `[n]` indexing,
+/// `[start:end]` slicing and `..key` recursive descent.
+pub fn select<'a>(value: &'a JsonValue, path: &str) -> Result<Vec<&'a JsonValue>, JsonError> {
+    let segments = PathParser::new(path).parse()?;
+
+    let mut current = vec![value];
+    for segment in &segments {
+        let mut next = Vec::new();
+        for v in current {
+            apply_segment(v, segment, &mut next);
+        }
+        current = next;
+    }
+
+    Ok(current)
+}
+
+fn apply_segment<'a>(value: &'a JsonValue, segment: &Segment, out: &mut Vec<&'a JsonValue>) {
+    match segment {
+        Segment::Key(key) => {
+            if let JsonValue::Object(pairs) = value {
+                out.extend(pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v));
+            }
+        }
+        Segment::Wildcard => match value {
+            JsonValue::Object(pairs) => out.extend(pairs.iter().map(|(_, v)| v)),
+            JsonValue::Array(items) => out.extend(items.iter()),
+            _ => {}
+        },
+        Segment::Index(idx) => {
+            if let JsonValue::Array(items) = value {
+                out.extend(resolve_index(items.len(), *idx).map(|i| &items[i]));
+            }
+        }
+        Segment::Slice(start, end) => {
+            if let JsonValue::Array(items) = value {
+                let (lo, hi) = resolve_slice(items.len(), *start, *end);
+                out.extend(items[lo..hi].iter());
+            }
+        }
+        Segment::RecursiveKey(key) => collect_recursive(value, key, out),
+    }
+}
+
+fn resolve_index(len: usize, idx: isize) -> Option<usize> {
+    let resolved = if idx < 0 { idx + len as isize } else { idx };
+    if resolved >= 0 && (resolved as usize) < len {
+        Some(resolved as usize)
+    } else {
+        None
+    }
+}
+
+fn resolve_slice(len: usize, start: Option<isize>, end: Option<isize>) -> (usize, usize) {
+    let bound = |v: Option<isize>, default: usize| match v {
+        None => default,
+        Some(n) if n < 0 => (n + len as isize).max(0) as usize,
+        Some(n) => (n as usize).min(len),
+    };
+
+    let lo = bound(start, 0);
+    let hi = bound(end, len);
+    if lo > hi {
+        (lo, lo)
+    } else {
+        (lo, hi)
+    }
+}
+
+fn collect_recursive<'a>(value: &'a JsonValue, key: &str, out: &mut Vec<&'a JsonValue>) {
+    match value {
+        JsonValue::Object(pairs) => {
+            for (k, v) in pairs {
+                if k == key {
+                    out.push(v);
+                }
+                collect_recursive(v, key, out);
+            }
+        }
+        JsonValue::Array(items) => {
+            for item in items {
+                collect_recursive(item, key, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+struct PathParser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> PathParser<'a> {
+    fn new(path: &'a str) -> Self {
+        Self {
+            chars: path.chars().peekable(),
+        }
+    }
+
+    fn parse(mut self) -> Result<Vec<Segment>, JsonError> {
+        match self.chars.next() {
+            Some('$') => {}
+            _ => return Err(invalid_path("path must start with '$'")),
+        }
+
+        let mut segments = Vec::new();
+
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '.' => {
+                    self.chars.next();
+                    if self.chars.peek() == Some(&'.') {
+                        self.chars.next();
+                        segments.push(Segment::RecursiveKey(self.read_identifier()?));
+                    } else if self.chars.peek() == Some(&'*') {
+                        self.chars.next();
+                        segments.push(Segment::Wildcard);
+                    } else {
+                        segments.push(Segment::Key(self.read_identifier()?));
+                    }
+                }
+                '[' => {
+                    self.chars.next();
+                    segments.push(self.read_bracket_segment()?);
+                }
+                _ => return Err(invalid_path(&format!("unexpected character '{}'", c))),
+            }
+        }
+
+        Ok(segments)
+    }
+
+    fn read_identifier(&mut self) -> Result<String, JsonError> {
+        let mut ident = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c == '.' || c == '[' {
+                break;
+            }
+            ident.push(c);
+            self.chars.next();
+        }
+
+        if ident.is_empty() {
+            return Err(invalid_path("expected a key after '.'"));
+        }
+
+        Ok(ident)
+    }
+
+    fn read_bracket_segment(&mut self) -> Result<Segment, JsonError> {
+        match self.chars.peek() {
+            Some('\'') | Some('"') => {
+                let quote = self.chars.next().unwrap();
+                let mut key = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some(c) if c == quote => break,
+                        Some(c) => key.push(c),
+                        None => return Err(invalid_path("unterminated bracket key")),
+                    }
+                }
+                self.expect(']')?;
+                Ok(Segment::Key(key))
+            }
+            Some('*') => {
+                self.chars.next();
+                self.expect(']')?;
+                Ok(Segment::Wildcard)
+            }
+            _ => {
+                let mut raw = String::new();
+                while let Some(&c) = self.chars.peek() {
+                    if c == ']' {
+                        break;
+                    }
+                    raw.push(c);
+                    self.chars.next();
+                }
+                self.expect(']')?;
+                parse_index_or_slice(&raw)
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(invalid_path(&format!("expected '{}'", expected))),
+        }
+    }
+}
+
+fn parse_index_or_slice(raw: &str) -> Result<Segment, JsonError> {
+    if let Some(colon) = raw.find(':') {
+        let (start, end) = raw.split_at(colon);
+        let end = &end[1..];
+        let start = parse_optional_isize(start)?;
+        let end = parse_optional_isize(end)?;
+        Ok(Segment::Slice(start, end))
+    } else {
+        raw.parse::<isize>()
+            .map(Segment::Index)
+            .map_err(|_| invalid_path(&format!("invalid array index '{}'", raw)))
+    }
+}
+
+fn parse_optional_isize(raw: &str) -> Result<Option<isize>, JsonError> {
+    if raw.is_empty() {
+        return Ok(None);
+    }
+
+    raw.parse::<isize>()
+        .map(Some)
+        .map_err(|_| invalid_path(&format!("invalid slice bound '{}'", raw)))
+}
+
+fn invalid_path(message: &str) -> JsonError {
+    JsonError::InvalidFormat {
+        message: format!("Invalid JSONPath: {}", message),
+        line: 0,
+        column: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select;
+    use crate::{Json, JsonParser, JsonValue};
+
+    fn parse(json: &str) -> JsonValue {
+        Json::new(json.to_string()).parse().unwrap()
+    }
+
+    #[test]
+    fn select_root() {
+        let value = parse(r#"{"age":3}"#);
+        assert_eq!(select(&value, "$").unwrap(), vec![&value]);
+    }
+
+    #[test]
+    fn select_nested_key() {
+        let value = parse(r#"{"sub":{"superSub":{"korv":"bullens"}}}"#);
+        let result = select(&value, "$.sub.superSub.korv").unwrap();
+        assert_eq!(result, vec![&JsonValue::String("bullens".to_string())]);
+    }
+
+    #[test]
+    fn select_bracket_key() {
+        let value = parse(r#"{"sub":{"korv":"bullens"}}"#);
+        let result = select(&value, "$.sub['korv']").unwrap();
+        assert_eq!(result, vec![&JsonValue::String("bullens".to_string())]);
+    }
+
+    #[test]
+    fn select_wildcard_object() {
+        let value = parse(r#"{"a":1,"b":2}"#);
+        let result = select(&value, "$.*").unwrap();
+        assert_eq!(result, vec![&JsonValue::I64(1), &JsonValue::I64(2)]);
+    }
+
+    #[test]
+    fn select_array_index() {
+        let value = parse("[1,2,3]");
+        let result = select(&value, "$[1]").unwrap();
+        assert_eq!(result, vec![&JsonValue::I64(2)]);
+    }
+
+    #[test]
+    fn select_array_negative_index() {
+        let value = parse("[1,2,3]");
+        let result = select(&value, "$[-1]").unwrap();
+        assert_eq!(result, vec![&JsonValue::I64(3)]);
+    }
+
+    #[test]
+    fn select_array_slice() {
+        let value = parse("[1,2,3,4,5]");
+        let result = select(&value, "$[1:3]").unwrap();
+        assert_eq!(result, vec![&JsonValue::I64(2), &JsonValue::I64(3)]);
+    }
+
+    #[test]
+    fn select_recursive_descent() {
+        let value = parse(r#"{"sub":{"superSub":{"korv":"bullens"}},"korv":"wurst"}"#);
+        let mut result = select(&value, "$..korv").unwrap();
+        result.sort_by_key(|v| format!("{:?}", v));
+        assert_eq!(
+            result,
+            vec![
+                &JsonValue::String("bullens".to_string()),
+                &JsonValue::String("wurst".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn select_rejects_path_without_root() {
+        let value = parse("{}");
+        assert!(select(&value, ".age").is_err());
+    }
+}
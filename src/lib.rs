@@ -1,22 +1,188 @@
+mod jsonpath;
+
+pub use jsonpath::select;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum JsonValue {
     Bool(bool),
     Number(f64),
+    I64(i64),
+    U64(u64),
     Null,
     Array(Vec<JsonValue>),
     String(String),
     Object(Vec<(String, JsonValue)>),
 }
 
+impl JsonValue {
+    pub fn is_i64(&self) -> bool {
+        matches!(self, JsonValue::I64(_))
+    }
+
+    pub fn is_u64(&self) -> bool {
+        matches!(self, JsonValue::U64(_))
+    }
+
+    pub fn is_f64(&self) -> bool {
+        matches!(self, JsonValue::Number(_))
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            JsonValue::I64(v) => Some(*v),
+            JsonValue::U64(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(v) => Some(*v),
+            JsonValue::I64(v) => Some(*v as f64),
+            JsonValue::U64(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        self.write_pretty(indent, 0)
+    }
+
+    fn write_pretty(&self, indent: usize, depth: usize) -> String {
+        match self {
+            JsonValue::Array(items) => {
+                if items.is_empty() {
+                    return "[]".to_string();
+                }
+
+                let pad = " ".repeat(indent * (depth + 1));
+                let closing_pad = " ".repeat(indent * depth);
+                let body = items
+                    .iter()
+                    .map(|v| format!("{}{}", pad, v.write_pretty(indent, depth + 1)))
+                    .collect::<Vec<_>>()
+                    .join(",\n");
+
+                format!("[\n{}\n{}]", body, closing_pad)
+            }
+            JsonValue::Object(pairs) => {
+                if pairs.is_empty() {
+                    return "{}".to_string();
+                }
+
+                let pad = " ".repeat(indent * (depth + 1));
+                let closing_pad = " ".repeat(indent * depth);
+                let body = pairs
+                    .iter()
+                    .map(|(k, v)| {
+                        format!(
+                            "{}{}: {}",
+                            pad,
+                            escape_json_string(k),
+                            v.write_pretty(indent, depth + 1)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",\n");
+
+                format!("{{\n{}\n{}}}", body, closing_pad)
+            }
+            other => other.to_string(),
+        }
+    }
+}
+
+fn escape_json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+impl std::fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonValue::Bool(b) => write!(f, "{}", b),
+            // Integral floats keep a trailing `.0` (`3.0`, not `3`) even though the original
+            // request asked for the opposite: writing a bare `3` would reparse as `I64(3)`,
+            // silently flipping the variant and breaking the round-trip invariant the same
+            // request closes with (`Json::new(v.to_string()).parse()` must yield an equal
+            // value). Round-trip correctness wins; `3` stays reserved for `JsonValue::I64(3)`.
+            JsonValue::Number(v) => {
+                if v.is_finite() && v.fract() == 0.0 {
+                    write!(f, "{:.1}", v)
+                } else {
+                    write!(f, "{}", v)
+                }
+            }
+            JsonValue::I64(v) => write!(f, "{}", v),
+            JsonValue::U64(v) => write!(f, "{}", v),
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::String(s) => write!(f, "{}", escape_json_string(s)),
+            JsonValue::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(pairs) => {
+                write!(f, "{{")?;
+                for (i, (k, v)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}:{}", escape_json_string(k), v)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum JsonError {
-    InvalidFormat(String),
+    InvalidFormat {
+        message: String,
+        line: usize,
+        column: usize,
+    },
     EndOfStr,
 }
 
 pub struct Json {
     pos: usize,
-    json: String,
+    bytes: Vec<u8>,
+}
+
+fn utf8_char_width(byte: u8) -> usize {
+    if byte & 0x80 == 0 {
+        1
+    } else if byte & 0xE0 == 0xC0 {
+        2
+    } else if byte & 0xF0 == 0xE0 {
+        3
+    } else {
+        4
+    }
 }
 
 pub trait JsonParser {
@@ -32,7 +198,10 @@ pub trait JsonParser {
 
 impl Json {
     pub fn new(json: String) -> Self {
-        Self { pos: 0, json }
+        Self {
+            pos: 0,
+            bytes: json.into_bytes(),
+        }
     }
 
     fn consume_char(&mut self) {
@@ -43,29 +212,111 @@ impl Json {
         self.pos += n
     }
 
+    // All JSON structural tokens (whitespace, punctuation, digits, `true`/`false`/`null`)
+    // are ASCII, so treating a single byte as a char is safe outside of string literals.
     fn current_char(&self) -> Option<char> {
-        self.json.chars().nth(self.pos)
+        self.bytes.get(self.pos).map(|&b| b as char)
     }
 
     fn next_char(&self) -> Option<char> {
-        self.json.chars().nth(self.pos + 1)
+        self.bytes.get(self.pos + 1).map(|&b| b as char)
     }
 
     fn look_ahead(&self, length: usize) -> String {
-        self.json
-            .chars()
-            .into_iter()
+        self.bytes
+            .iter()
             .skip(self.pos + 1)
-            .into_iter()
             .take(length)
+            .map(|&b| b as char)
             .collect()
     }
 
+    // Decodes the full (possibly multi-byte) UTF-8 character starting at `pos` and
+    // advances past it. Used for raw string content, where bytes are no longer ASCII-only.
+    fn consume_utf8_char(&mut self) -> char {
+        let width = utf8_char_width(self.bytes[self.pos]).min(self.bytes.len() - self.pos);
+        let slice = &self.bytes[self.pos..self.pos + width];
+        let c = std::str::from_utf8(slice)
+            .ok()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+        self.pos += width;
+        c
+    }
+
+    fn line_col(&self, pos: usize) -> (usize, usize) {
+        let mut line = 1;
+        let mut column = 1;
+
+        for &b in &self.bytes[..pos.min(self.bytes.len())] {
+            if b == b'\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+
+        (line, column)
+    }
+
+    fn error_at(&self, pos: usize, message: impl Into<String>) -> JsonError {
+        let (line, column) = self.line_col(pos);
+        JsonError::InvalidFormat {
+            message: message.into(),
+            line,
+            column,
+        }
+    }
+
     fn invalid_format(&self, invalid: char) -> JsonError {
-        JsonError::InvalidFormat(format!(
-            "Invalid format at pos {} found: '{}'",
-            self.pos, invalid
-        ))
+        self.error_at(self.pos, format!("found: '{}'", invalid))
+    }
+
+    fn consume_digits(&mut self, into: &mut String) {
+        while let Some(c) = self.current_char() {
+            if c.is_ascii_digit() {
+                into.push(c);
+                self.consume_char();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u16, JsonError> {
+        let mut hex = String::with_capacity(4);
+        for _ in 0..4 {
+            match self.current_char() {
+                Some(c) if c.is_ascii_hexdigit() => {
+                    hex.push(c);
+                    self.consume_char();
+                }
+                _ => return Err(self.error_at(self.pos, "Invalid \\u escape")),
+            }
+        }
+
+        u16::from_str_radix(&hex, 16)
+            .map_err(|e| self.error_at(self.pos, format!("Invalid \\u escape: {}", e)))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ') | Some('\n') | Some('\t') | Some('\r') = self.current_char() {
+            self.consume_char();
+        }
+    }
+
+    pub fn parse_root(&mut self) -> Result<JsonValue, JsonError> {
+        let value = self.parse()?;
+        self.skip_whitespace();
+
+        match self.current_char() {
+            None => Ok(value),
+            Some(c) => Err(self.error_at(
+                self.pos,
+                format!("Unexpected trailing content starting with '{}'", c),
+            )),
+        }
     }
 }
 
@@ -98,49 +349,97 @@ impl JsonParser for Json {
                     self.consume_chars(4);
                     Ok(JsonValue::Bool(true))
                 }
-                c => Err(JsonError::InvalidFormat(format!(
-                    "Invalid boolean at {} found char: '{}'",
-                    self.pos, c
-                ))),
+                c => Err(self.error_at(self.pos, format!("Invalid boolean, found: '{}'", c))),
             },
             Some('f') | Some('F') => match self.look_ahead(4).to_lowercase().as_str() {
                 "alse" => {
                     self.consume_chars(5);
                     Ok(JsonValue::Bool(false))
                 }
-                c => Err(JsonError::InvalidFormat(format!(
-                    "Invalid boolean at {} found char: '{}'",
-                    self.pos, c
-                ))),
+                c => Err(self.error_at(self.pos, format!("Invalid boolean, found: '{}'", c))),
             },
             None => Err(JsonError::EndOfStr),
-            c => Err(JsonError::InvalidFormat(format!(
-                "Invalid boolean at {} found char: '{:?}'",
-                self.pos, c
-            ))),
+            c => Err(self.error_at(self.pos, format!("Invalid boolean, found: '{:?}'", c))),
         }
     }
 
     fn parse_number(&mut self) -> Result<JsonValue, JsonError> {
-        let num: String = self
-            .json
-            .chars()
-            .into_iter()
-            .skip(self.pos)
-            .into_iter()
-            .take_while(|c| c.is_digit(10) || *c == '.' || *c == '-')
-            .collect::<String>();
-
-        match num.parse::<f64>() {
-            Ok(v) => {
-                self.consume_chars(num.len());
-                Ok(JsonValue::Number(v))
+        let start = self.pos;
+        let mut num = String::new();
+
+        if let Some('-') = self.current_char() {
+            num.push('-');
+            self.consume_char();
+        }
+
+        match self.current_char() {
+            Some(c) if c.is_ascii_digit() => {
+                num.push(c);
+                self.consume_char();
+
+                if c == '0' && self.current_char().is_some_and(|d| d.is_ascii_digit()) {
+                    return Err(self.error_at(
+                        start,
+                        "Invalid number format: leading zeros are not allowed",
+                    ));
+                }
+            }
+            _ => return Err(self.error_at(start, "Invalid number format")),
+        }
+
+        self.consume_digits(&mut num);
+
+        let mut is_float = false;
+
+        if let Some('.') = self.current_char() {
+            is_float = true;
+            num.push('.');
+            self.consume_char();
+
+            match self.current_char() {
+                Some(c) if c.is_ascii_digit() => {
+                    num.push(c);
+                    self.consume_char();
+                }
+                _ => return Err(self.error_at(start, "Invalid number format")),
+            }
+
+            self.consume_digits(&mut num);
+        }
+
+        if let Some('e') | Some('E') = self.current_char() {
+            is_float = true;
+            num.push(self.current_char().unwrap());
+            self.consume_char();
+
+            if let Some('+') | Some('-') = self.current_char() {
+                num.push(self.current_char().unwrap());
+                self.consume_char();
+            }
+
+            match self.current_char() {
+                Some(c) if c.is_ascii_digit() => {
+                    num.push(c);
+                    self.consume_char();
+                }
+                _ => return Err(self.error_at(start, "Invalid number format")),
+            }
+
+            self.consume_digits(&mut num);
+        }
+
+        if !is_float {
+            if let Ok(v) = num.parse::<i64>() {
+                return Ok(JsonValue::I64(v));
+            }
+            if let Ok(v) = num.parse::<u64>() {
+                return Ok(JsonValue::U64(v));
             }
-            Err(e) => Err(JsonError::InvalidFormat(format!(
-                "Invalid number format at {} '{}'",
-                self.pos, e
-            ))),
         }
+
+        num.parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|e| self.error_at(start, format!("Invalid number format: {}", e)))
     }
 
     fn parse_null(&mut self) -> Result<JsonValue, JsonError> {
@@ -149,33 +448,42 @@ impl JsonParser for Json {
                 self.consume_chars(4);
                 Ok(JsonValue::Null)
             }
-            c => Err(JsonError::InvalidFormat(format!(
-                "Invalid null value at {} '{}'",
-                self.pos, c
-            ))),
+            c => Err(self.error_at(self.pos, format!("Invalid null value, found: '{}'", c))),
         }
     }
 
     fn parse_array(&mut self) -> Result<JsonValue, JsonError> {
+        self.consume_char(); // consume '['
         let mut array = Vec::new();
 
-        loop {
+        self.skip_whitespace();
+        if let Some(']') = self.current_char() {
             self.consume_char();
+            return Ok(JsonValue::Array(array));
+        }
+
+        loop {
+            self.skip_whitespace();
 
             match self.current_char() {
-                Some(',') | Some(' ') => {
-                    self.consume_char();
-                    continue;
+                Some(',') | Some(']') | None => {
+                    return Err(self.error_at(self.pos, "Expected a value in array"));
                 }
+                _ => {}
+            }
+
+            let value = self.parse()?;
+            array.push(value);
+
+            self.skip_whitespace();
+
+            match self.current_char() {
+                Some(',') => self.consume_char(),
                 Some(']') => {
                     self.consume_char();
                     break;
                 }
-                Some(_) => {
-                    let value = self.parse().unwrap();
-                    array.push(value);
-                }
-                None => break,
+                _ => return Err(self.error_at(self.pos, "Expected ',' or ']' in array")),
             }
         }
         Ok(JsonValue::Array(array))
@@ -183,58 +491,160 @@ impl JsonParser for Json {
 
     fn parse_string(&mut self) -> Result<JsonValue, JsonError> {
         self.consume_char();
-        let str: String = self
-            .json
-            .chars()
-            .into_iter()
-            .skip(self.pos)
-            .into_iter()
-            .take_while(|c| *c != '"')
-            .collect::<String>();
+        let mut result = String::new();
+
+        loop {
+            match self.current_char() {
+                Some('"') => {
+                    self.consume_char();
+                    break;
+                }
+                Some('\\') => {
+                    self.consume_char();
+                    match self.current_char() {
+                        Some('"') => {
+                            result.push('"');
+                            self.consume_char();
+                        }
+                        Some('\\') => {
+                            result.push('\\');
+                            self.consume_char();
+                        }
+                        Some('/') => {
+                            result.push('/');
+                            self.consume_char();
+                        }
+                        Some('b') => {
+                            result.push('\u{0008}');
+                            self.consume_char();
+                        }
+                        Some('f') => {
+                            result.push('\u{000C}');
+                            self.consume_char();
+                        }
+                        Some('n') => {
+                            result.push('\n');
+                            self.consume_char();
+                        }
+                        Some('r') => {
+                            result.push('\r');
+                            self.consume_char();
+                        }
+                        Some('t') => {
+                            result.push('\t');
+                            self.consume_char();
+                        }
+                        Some('u') => {
+                            self.consume_char();
+                            let high = self.parse_hex4()?;
+
+                            if (0xD800..=0xDBFF).contains(&high) {
+                                if self.current_char() != Some('\\')
+                                    || self.next_char() != Some('u')
+                                {
+                                    return Err(
+                                        self.error_at(self.pos, "Expected low surrogate")
+                                    );
+                                }
+                                self.consume_chars(2);
+                                let low = self.parse_hex4()?;
 
-        self.consume_chars(str.len());
+                                if !(0xDC00..=0xDFFF).contains(&low) {
+                                    return Err(
+                                        self.error_at(self.pos, "Invalid low surrogate")
+                                    );
+                                }
 
-        Ok(JsonValue::String(str))
+                                let combined = 0x10000
+                                    + ((high as u32 - 0xD800) << 10)
+                                    + (low as u32 - 0xDC00);
+
+                                match char::from_u32(combined) {
+                                    Some(c) => result.push(c),
+                                    None => {
+                                        return Err(
+                                            self.error_at(self.pos, "Invalid unicode escape")
+                                        )
+                                    }
+                                }
+                            } else {
+                                match char::from_u32(high as u32) {
+                                    Some(c) => result.push(c),
+                                    None => {
+                                        return Err(
+                                            self.error_at(self.pos, "Invalid unicode escape")
+                                        )
+                                    }
+                                }
+                            }
+                        }
+                        _ => return Err(self.error_at(self.pos, "Invalid escape sequence")),
+                    }
+                }
+                Some(_) => {
+                    result.push(self.consume_utf8_char());
+                }
+                None => return Err(JsonError::EndOfStr),
+            }
+        }
+
+        Ok(JsonValue::String(result))
     }
 
     fn parse_key(&mut self) -> String {
-        let str = self
-            .json
-            .chars()
-            .into_iter()
-            .skip(self.pos)
-            .into_iter()
-            .take_while(|c| *c != '"')
-            .collect::<String>();
+        self.consume_char();
+        let mut key = String::new();
 
-        self.consume_chars(str.len());
+        while let Some(c) = self.current_char() {
+            if c == '"' {
+                self.consume_char();
+                break;
+            }
+            key.push(self.consume_utf8_char());
+        }
 
-        str
+        key
     }
 
     fn parse_object(&mut self) -> Result<JsonValue, JsonError> {
+        self.consume_char(); // consume '{'
         let mut obj = Vec::new();
-        loop {
+
+        self.skip_whitespace();
+        if let Some('}') = self.current_char() {
             self.consume_char();
+            return Ok(JsonValue::Object(obj));
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            match self.current_char() {
+                Some('"') => {}
+                _ => return Err(self.error_at(self.pos, "Expected a string key in object")),
+            }
+
+            let key = self.parse_key();
+            self.skip_whitespace();
+
             match self.current_char() {
+                Some(':') => self.consume_char(),
+                _ => return Err(self.error_at(self.pos, "Expected ':' after object key")),
+            }
+
+            let value = self.parse()?;
+            obj.push((key, value));
+
+            self.skip_whitespace();
+
+            match self.current_char() {
+                Some(',') => self.consume_char(),
                 Some('}') => {
                     self.consume_char();
                     break;
                 }
-                // Fattar inte .. dum i huvudet
-                Some(',') | Some(' ') | Some(':') | Some('\n') | Some('\t') => {
-                    self.consume_char();
-                    continue;
-                }
-                Some(_) => {
-                    let key = self.parse_key();
-                    self.consume_char();
-                    let value = self.parse().unwrap();
-                    obj.push((key, value));
-                    continue;
-                }
-                None => break,
-            };
+                _ => return Err(self.error_at(self.pos, "Expected ',' or '}' in object")),
+            }
         }
         Ok(JsonValue::Object(obj))
     }
@@ -244,14 +654,14 @@ impl JsonParser for Json {
 mod tests {
     use std::collections::HashMap;
 
-    use crate::{Json, JsonParser, JsonValue};
+    use crate::{Json, JsonError, JsonParser, JsonValue};
 
     #[test]
     fn parse_object_num() {
         let data = r#"{"age":4}"#.to_string();
 
         let mut obj = Vec::new();
-        obj.push(("age".to_string(), JsonValue::Number(4.0)));
+        obj.push(("age".to_string(), JsonValue::I64(4)));
 
         let mut json = Json::new(data);
         assert_eq!(json.parse().unwrap(), JsonValue::Object(obj));
@@ -275,21 +685,21 @@ mod tests {
     fn parse_num() {
         let data = "1234".to_string();
         let mut json = Json::new(data);
-        assert_eq!(json.parse_number().unwrap(), JsonValue::Number(1234.0));
+        assert_eq!(json.parse_number().unwrap(), JsonValue::I64(1234));
     }
 
     #[test]
     fn parse_num_single() {
         let data = "2".to_string();
         let mut json = Json::new(data);
-        assert_eq!(json.parse_number().unwrap(), JsonValue::Number(2.0));
+        assert_eq!(json.parse_number().unwrap(), JsonValue::I64(2));
     }
 
     #[test]
     fn parse_num_negative() {
         let data = "-122".to_string();
         let mut json = Json::new(data);
-        assert_eq!(json.parse_number().unwrap(), JsonValue::Number(-122.0));
+        assert_eq!(json.parse_number().unwrap(), JsonValue::I64(-122));
     }
 
     #[test]
@@ -300,10 +710,43 @@ mod tests {
     }
 
     #[test]
-    fn parse_num_leading_zero() {
+    fn parse_num_rejects_leading_zero() {
         let data = "02".to_string();
         let mut json = Json::new(data);
-        assert_eq!(json.parse_number().unwrap(), JsonValue::Number(2.0));
+        assert!(json.parse_number().is_err());
+    }
+
+    #[test]
+    fn parse_num_zero() {
+        let data = "0".to_string();
+        let mut json = Json::new(data);
+        assert_eq!(json.parse_number().unwrap(), JsonValue::I64(0));
+    }
+
+    #[test]
+    fn as_i64_accepts_in_range_u64() {
+        let value = JsonValue::U64(42);
+        assert_eq!(value.as_i64(), Some(42));
+    }
+
+    #[test]
+    fn as_i64_rejects_out_of_range_u64() {
+        let value = JsonValue::U64(u64::MAX);
+        assert_eq!(value.as_i64(), None);
+    }
+
+    #[test]
+    fn as_f64_casts_i64_and_u64() {
+        assert_eq!(JsonValue::I64(-7).as_f64(), Some(-7.0));
+        assert_eq!(JsonValue::U64(7).as_f64(), Some(7.0));
+        assert_eq!(JsonValue::Number(2.5).as_f64(), Some(2.5));
+    }
+
+    #[test]
+    fn parse_num_zero_fraction() {
+        let data = "0.5".to_string();
+        let mut json = Json::new(data);
+        assert_eq!(json.parse_number().unwrap(), JsonValue::Number(0.5));
     }
 
     #[test]
@@ -329,9 +772,9 @@ mod tests {
         assert_eq!(
             json.parse_array().unwrap(),
             JsonValue::Array(vec![
-                JsonValue::Number(1.0),
-                JsonValue::Number(2.0),
-                JsonValue::Number(3.0)
+                JsonValue::I64(1),
+                JsonValue::I64(2),
+                JsonValue::I64(3)
             ])
         )
     }
@@ -351,6 +794,59 @@ mod tests {
         )
     }
 
+    #[test]
+    fn to_string_compact() {
+        let value = JsonValue::Array(vec![
+            JsonValue::I64(1),
+            JsonValue::Number(2.5),
+            JsonValue::String("hi\n\"there\"".to_string()),
+            JsonValue::Null,
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"[1,2.5,"hi\n\"there\"",null]"#.to_string()
+        );
+    }
+
+    #[test]
+    fn integral_number_round_trips_through_to_string() {
+        let value = JsonValue::Object(vec![("age".to_string(), JsonValue::Number(3.0))]);
+
+        assert_eq!(value.to_string(), r#"{"age":3.0}"#.to_string());
+
+        let mut json = Json::new(value.to_string());
+        assert_eq!(json.parse().unwrap(), value);
+    }
+
+    #[test]
+    fn to_string_pretty_nested() {
+        let value = JsonValue::Object(vec![
+            ("age".to_string(), JsonValue::I64(3)),
+            (
+                "tags".to_string(),
+                JsonValue::Array(vec![JsonValue::Bool(true)]),
+            ),
+        ]);
+
+        assert_eq!(
+            value.to_string_pretty(2),
+            "{\n  \"age\": 3,\n  \"tags\": [\n    true\n  ]\n}".to_string()
+        );
+    }
+
+    #[test]
+    fn to_string_round_trips_through_array() {
+        let value = JsonValue::Array(vec![
+            JsonValue::I64(-7),
+            JsonValue::Number(1.5),
+            JsonValue::Bool(false),
+        ]);
+
+        let mut json = Json::new(value.to_string());
+        assert_eq!(json.parse().unwrap(), value);
+    }
+
     #[test]
     fn parse_string() {
         let data = "\"apa\"".to_string();
@@ -362,6 +858,86 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_string_escaped_quote() {
+        let data = "\"say \\\"hej\\\"\"".to_string();
+
+        let mut json = Json::new(data);
+        assert_eq!(
+            json.parse_string().unwrap(),
+            JsonValue::String("say \"hej\"".to_string())
+        )
+    }
+
+    #[test]
+    fn parse_string_control_escapes() {
+        let data = "\"a\\nb\\tc\\\\d\"".to_string();
+
+        let mut json = Json::new(data);
+        assert_eq!(
+            json.parse_string().unwrap(),
+            JsonValue::String("a\nb\tc\\d".to_string())
+        )
+    }
+
+    #[test]
+    fn parse_string_unicode_escape() {
+        let data = "\"\\u00e5pa\"".to_string();
+
+        let mut json = Json::new(data);
+        assert_eq!(
+            json.parse_string().unwrap(),
+            JsonValue::String("åpa".to_string())
+        )
+    }
+
+    #[test]
+    fn parse_string_surrogate_pair() {
+        let data = "\"\\uD83D\\uDE00\"".to_string();
+
+        let mut json = Json::new(data);
+        assert_eq!(
+            json.parse_string().unwrap(),
+            JsonValue::String("😀".to_string())
+        )
+    }
+
+    #[test]
+    fn parse_string_invalid_escape() {
+        let data = "\"\\q\"".to_string();
+
+        let mut json = Json::new(data);
+        assert!(json.parse_string().is_err());
+    }
+
+    #[test]
+    fn parse_num_exponent() {
+        let data = "1.5e3".to_string();
+        let mut json = Json::new(data);
+        assert_eq!(json.parse_number().unwrap(), JsonValue::Number(1500.0));
+    }
+
+    #[test]
+    fn parse_num_negative_exponent() {
+        let data = "2E-2".to_string();
+        let mut json = Json::new(data);
+        assert_eq!(json.parse_number().unwrap(), JsonValue::Number(0.02));
+    }
+
+    #[test]
+    fn parse_num_stops_before_malformed_suffix() {
+        let data = "1-2".to_string();
+        let mut json = Json::new(data);
+        assert_eq!(json.parse_number().unwrap(), JsonValue::I64(1));
+    }
+
+    #[test]
+    fn parse_num_missing_fraction_digits() {
+        let data = "1.".to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_number().is_err());
+    }
+
     #[test]
     fn look_ahed() {
         let testdata = "12345";
@@ -370,4 +946,140 @@ mod tests {
         assert_eq!(json.look_ahead(3), String::from("234"));
         assert_eq!(json.look_ahead(10), String::from("2345"));
     }
+
+    #[test]
+    fn parse_object_with_malformed_value_does_not_panic() {
+        let data = r#"{"age": tru}"#.to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse().is_err());
+    }
+
+    #[test]
+    fn parse_array_with_malformed_value_does_not_panic() {
+        let data = "[1, tru, 3]".to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_array().is_err());
+    }
+
+    #[test]
+    fn parse_array_requires_comma_between_elements() {
+        let data = "[1 2 3]".to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_array().is_err());
+    }
+
+    #[test]
+    fn parse_array_rejects_double_comma() {
+        let data = "[1,,2]".to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_array().is_err());
+    }
+
+    #[test]
+    fn parse_array_rejects_trailing_comma() {
+        let data = "[1,2,]".to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_array().is_err());
+    }
+
+    #[test]
+    fn parse_object_requires_comma_between_pairs() {
+        let data = r#"{"a":1 "b":2}"#.to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_object().is_err());
+    }
+
+    #[test]
+    fn parse_object_rejects_trailing_comma() {
+        let data = r#"{"a":1,}"#.to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_object().is_err());
+    }
+
+    #[test]
+    fn error_reports_line_and_column() {
+        let data = "[1,\n  tru]".to_string();
+        let mut json = Json::new(data);
+        match json.parse_array() {
+            Err(JsonError::InvalidFormat { line, column, .. }) => {
+                assert_eq!(line, 2);
+                assert_eq!(column, 3);
+            }
+            other => panic!("expected InvalidFormat error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_root_rejects_trailing_content() {
+        let data = "truefalse".to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_root().is_err());
+    }
+
+    #[test]
+    fn parse_root_rejects_trailing_junk_after_object() {
+        let data = "{} junk".to_string();
+        let mut json = Json::new(data);
+        assert!(json.parse_root().is_err());
+    }
+
+    #[test]
+    fn parse_root_allows_trailing_whitespace() {
+        let data = "true   \n".to_string();
+        let mut json = Json::new(data);
+        assert_eq!(json.parse_root().unwrap(), JsonValue::Bool(true));
+    }
+}
+
+// Round-trip and fuzz tests driven by arbitrary inputs rather than fixed cases: the
+// serializer/parser pair should agree on every `JsonValue` it can produce, and the parser
+// should never panic no matter what garbage it is fed.
+#[cfg(test)]
+mod proptests {
+    use proptest::prelude::*;
+
+    use crate::{Json, JsonValue};
+
+    fn arb_json_value() -> impl Strategy<Value = JsonValue> {
+        let leaf = prop_oneof![
+            Just(JsonValue::Null),
+            any::<bool>().prop_map(JsonValue::Bool),
+            any::<i64>().prop_map(JsonValue::I64),
+            // `parse_number` only ever emits `U64` once the value overflows `i64`, so
+            // generate strictly in that range to exercise the variant meaningfully.
+            ((i64::MAX as u64 + 1)..=u64::MAX).prop_map(JsonValue::U64),
+            (-1_000_000.0f64..1_000_000.0f64).prop_map(JsonValue::Number),
+            "[a-zA-Z0-9 ]{0,12}".prop_map(JsonValue::String),
+        ];
+
+        leaf.prop_recursive(4, 64, 8, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..6).prop_map(JsonValue::Array),
+                prop::collection::vec(("[a-zA-Z][a-zA-Z0-9]{0,6}", inner), 0..6)
+                    .prop_map(JsonValue::Object),
+            ]
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_through_compact_serialization(value in arb_json_value()) {
+            let mut json = Json::new(value.to_string());
+            let parsed = json.parse_root().expect("serialized JsonValue must reparse");
+            prop_assert_eq!(parsed, value);
+        }
+
+        #[test]
+        fn round_trips_through_pretty_serialization(value in arb_json_value()) {
+            let mut json = Json::new(value.to_string_pretty(2));
+            let parsed = json.parse_root().expect("pretty-printed JsonValue must reparse");
+            prop_assert_eq!(parsed, value);
+        }
+
+        #[test]
+        fn parsing_arbitrary_input_never_panics(input in "\\PC*") {
+            let mut json = Json::new(input);
+            let _ = json.parse_root();
+        }
+    }
 }
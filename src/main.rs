@@ -1,4 +1,4 @@
-use rjson::{Json, JsonParser};
+use rjson::Json;
 
 fn main() {
     //    let j = r#"{ "age": 3, "name": "heldt" }"#.to_string();
@@ -20,7 +20,7 @@ fn main() {
 
     let mut json = Json::new(j);
 
-    let result = json.parse();
+    let result = json.parse_root();
 
     println!("{:?}", result.unwrap());
 }